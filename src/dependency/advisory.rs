@@ -0,0 +1,102 @@
+use semver::{Version, VersionReq};
+
+use crate::finding::Finding;
+use crate::severity::Severity;
+
+use super::lockfile::LockedPackage;
+
+/// What's wrong with a given crate version, mirroring the three categories
+/// the RustSec advisory database tracks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdvisoryKind {
+    Vulnerability,
+    Yanked,
+    Unmaintained,
+}
+
+/// One entry from the offline RustSec advisory snapshot: a crate, the
+/// version range it affects, and why.
+pub struct Advisory {
+    pub package: &'static str,
+    /// A `semver::VersionReq` matching the *affected* versions.
+    pub affected: &'static str,
+    pub id: &'static str,
+    pub kind: AdvisoryKind,
+    pub description: &'static str,
+}
+
+/// A small offline snapshot of the RustSec advisory database, vendored so
+/// scans work without network access. This is a point-in-time subset meant
+/// to be refreshed from <https://github.com/RustSec/advisory-db>; it is not
+/// exhaustive.
+pub const OFFLINE_SNAPSHOT: &[Advisory] = &[
+    Advisory {
+        package: "time",
+        affected: "<0.2.23",
+        id: "RUSTSEC-2020-0071",
+        kind: AdvisoryKind::Vulnerability,
+        description: "potential segfault in the time crate's `localtime_r` usage",
+    },
+    Advisory {
+        package: "openssl",
+        affected: "<0.10.55",
+        id: "RUSTSEC-2023-0044",
+        kind: AdvisoryKind::Vulnerability,
+        description: "NULL pointer dereference via invalid ASN.1 input",
+    },
+    Advisory {
+        package: "net2",
+        affected: "*",
+        id: "RUSTSEC-2020-0016",
+        kind: AdvisoryKind::Unmaintained,
+        description: "net2 is unmaintained; use the `socket2` crate instead",
+    },
+    Advisory {
+        package: "tempdir",
+        affected: "*",
+        id: "RUSTSEC-2018-0017",
+        kind: AdvisoryKind::Unmaintained,
+        description: "tempdir is unmaintained; use the `tempfile` crate instead",
+    },
+];
+
+fn severity_for(kind: AdvisoryKind) -> Severity {
+    match kind {
+        AdvisoryKind::Vulnerability => Severity::High,
+        AdvisoryKind::Yanked => Severity::Medium,
+        AdvisoryKind::Unmaintained => Severity::Low,
+    }
+}
+
+/// Matches every locked package against the offline advisory snapshot,
+/// returning a finding for each hit.
+pub fn check(packages: &[LockedPackage]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    for package in packages {
+        let Ok(version) = Version::parse(&package.version) else {
+            continue;
+        };
+        for advisory in OFFLINE_SNAPSHOT {
+            if advisory.package != package.name {
+                continue;
+            }
+            let Ok(req) = VersionReq::parse(advisory.affected) else {
+                continue;
+            };
+            if !req.matches(&version) {
+                continue;
+            }
+            findings.push(Finding::new(
+                "dependency-advisory",
+                severity_for(advisory.kind),
+                format!(
+                    "{}@{} is affected by {}: {}",
+                    package.name, package.version, advisory.id, advisory.description
+                ),
+                "Cargo.lock",
+                0,
+            ));
+        }
+    }
+    findings
+}
@@ -0,0 +1,30 @@
+use std::fs;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+/// A single resolved dependency from `Cargo.lock`, i.e. one `[[package]]`
+/// entry.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LockedPackage {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub source: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawLockfile {
+    #[serde(rename = "package", default)]
+    packages: Vec<LockedPackage>,
+}
+
+/// Parses a `Cargo.lock` file and returns its fully resolved dependency
+/// graph (flat — `Cargo.lock` already lists every transitive crate once).
+pub fn parse(path: &Path) -> Result<Vec<LockedPackage>> {
+    let text = fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let raw: RawLockfile =
+        toml::from_str(&text).with_context(|| format!("parsing {}", path.display()))?;
+    Ok(raw.packages)
+}
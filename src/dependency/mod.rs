@@ -0,0 +1,21 @@
+//! Supply-chain auditing: resolving `Cargo.lock` against an offline
+//! snapshot of the RustSec advisory database, and recording dependency
+//! provenance so a scanned binary can be re-audited without its source
+//! tree.
+
+pub mod advisory;
+pub mod lockfile;
+pub mod provenance;
+
+use std::path::Path;
+
+use anyhow::Result;
+
+use crate::finding::Finding;
+
+/// Parses `Cargo.lock` at `path` and checks every resolved crate against
+/// the offline advisory snapshot.
+pub fn audit_lockfile(path: &Path) -> Result<Vec<Finding>> {
+    let packages = lockfile::parse(path)?;
+    Ok(advisory::check(&packages))
+}
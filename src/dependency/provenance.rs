@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+
+use super::lockfile::LockedPackage;
+
+/// A compact, serializable record of a resolved dependency tree: just
+/// enough (name, version, source) to re-run the advisory check later
+/// against a refreshed snapshot, without needing the original source tree
+/// or `Cargo.lock` on hand.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProvenanceRecord {
+    pub packages: Vec<ProvenancePackage>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProvenancePackage {
+    pub name: String,
+    pub version: String,
+    pub source: Option<String>,
+}
+
+impl ProvenanceRecord {
+    pub fn from_packages(packages: &[LockedPackage]) -> Self {
+        ProvenanceRecord {
+            packages: packages
+                .iter()
+                .map(|p| ProvenancePackage {
+                    name: p.name.clone(),
+                    version: p.version.clone(),
+                    source: p.source.clone(),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use crate::severity::Severity;
+
+/// A single issue surfaced by a rule, tied to the exact file and line that
+/// triggered it so a user can jump straight to the offending code.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Finding {
+    pub rule_id: &'static str,
+    pub severity: Severity,
+    pub message: String,
+    pub file: PathBuf,
+    pub line: usize,
+}
+
+impl Finding {
+    pub fn new(
+        rule_id: &'static str,
+        severity: Severity,
+        message: impl Into<String>,
+        file: impl Into<PathBuf>,
+        line: usize,
+    ) -> Self {
+        Finding {
+            rule_id,
+            severity,
+            message: message.into(),
+            file: file.into(),
+            line,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+//! SkillGuard: a static analysis scanner that flags dangerous patterns in
+//! Rust source code, such as shell execution, filesystem tampering,
+//! outbound network access, and unsafe code.
+
+pub mod dependency;
+pub mod finding;
+pub mod rules;
+pub mod scanner;
+pub mod severity;
+pub mod taint;
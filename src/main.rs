@@ -0,0 +1,112 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use clap::{Parser, Subcommand};
+
+/// SkillGuard: scan Rust source for dangerous patterns and audit
+/// dependencies for known supply-chain issues.
+#[derive(Parser)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Scan a file or directory of Rust source for dangerous patterns.
+    Scan {
+        /// File or directory to scan.
+        path: PathBuf,
+    },
+    /// Check a `Cargo.lock` against the offline RustSec advisory snapshot.
+    Audit {
+        /// Path to the `Cargo.lock` to audit.
+        #[arg(default_value = "Cargo.lock")]
+        lockfile: PathBuf,
+    },
+    /// Record the resolved dependency tree as a compact provenance record.
+    Provenance {
+        /// Path to the `Cargo.lock` to record.
+        #[arg(default_value = "Cargo.lock")]
+        lockfile: PathBuf,
+        /// Where to write the JSON provenance record.
+        #[arg(default_value = "provenance.json")]
+        output: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Scan { path } => run_scan(&path),
+        Command::Audit { lockfile } => run_audit(&lockfile),
+        Command::Provenance { lockfile, output } => run_provenance(&lockfile, &output),
+    }
+}
+
+fn run_scan(path: &std::path::Path) -> ExitCode {
+    let findings = if path.is_dir() {
+        skillguard::scanner::scan_path(path)
+    } else {
+        skillguard::scanner::scan_file(path)
+    };
+    report(findings)
+}
+
+fn run_audit(lockfile: &std::path::Path) -> ExitCode {
+    report(skillguard::dependency::audit_lockfile(lockfile))
+}
+
+fn run_provenance(lockfile: &std::path::Path, output: &std::path::Path) -> ExitCode {
+    let packages = match skillguard::dependency::lockfile::parse(lockfile) {
+        Ok(packages) => packages,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let record = skillguard::dependency::provenance::ProvenanceRecord::from_packages(&packages);
+    let json = match record.to_json() {
+        Ok(json) => json,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            return ExitCode::FAILURE;
+        }
+    };
+    if let Err(err) = std::fs::write(output, json) {
+        eprintln!("error: {err:#}");
+        return ExitCode::FAILURE;
+    }
+    println!("wrote provenance record for {} packages to {}", packages.len(), output.display());
+    ExitCode::SUCCESS
+}
+
+fn report(findings: anyhow::Result<Vec<skillguard::finding::Finding>>) -> ExitCode {
+    let mut findings = match findings {
+        Ok(findings) => findings,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if findings.is_empty() {
+        println!("no findings");
+        return ExitCode::SUCCESS;
+    }
+
+    findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
+    for finding in &findings {
+        println!(
+            "[{}] {}:{} {} - {}",
+            finding.severity,
+            finding.file.display(),
+            finding.line,
+            finding.rule_id,
+            finding.message
+        );
+    }
+
+    ExitCode::FAILURE
+}
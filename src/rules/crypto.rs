@@ -0,0 +1,115 @@
+use std::path::Path;
+
+use syn::{Expr, ExprCall, Lit};
+
+use super::{call_path_segments, line_of};
+use crate::finding::Finding;
+use crate::severity::Severity;
+
+/// Hash/cipher constructors from crates that implement algorithms which are
+/// cryptographically broken for anything but a checksum: MD5, SHA-1, DES,
+/// and RC4.
+const WEAK_ALGORITHMS: &[&[&str]] = &[
+    &["md5", "compute"],
+    &["Md5", "new"],
+    &["Sha1", "new"],
+    &["Des", "new"],
+    &["Rc4", "new"],
+];
+
+/// Type-path keywords for block ciphers, used to recognize an IV/nonce/salt
+/// argument to a cipher constructor without knowing the crate's exact
+/// parameter names.
+const CIPHER_KEYWORDS: &[&str] = &["aes", "des", "chacha", "cbc", "ctr", "gcm", "ecb"];
+
+/// Flags weak hash/cipher algorithms, undersized RSA keys, ECB mode, and
+/// hardcoded IVs/nonces/salts.
+pub fn check(call: &ExprCall, file: &Path) -> Option<Finding> {
+    let segments = call_path_segments(&call.func)?;
+
+    if WEAK_ALGORITHMS.iter().any(|alg| super::path_ends_with(&segments, alg)) {
+        return Some(Finding::new(
+            "weak-crypto-algorithm",
+            Severity::Medium,
+            "uses a cryptographically broken hash or cipher (MD5/SHA-1/DES/RC4) outside of a checksum",
+            file,
+            line_of(call),
+        ));
+    }
+
+    if super::path_ends_with(&segments, &["RsaPrivateKey", "new"]) {
+        if let Some(bits) = call.args.last().and_then(literal_int) {
+            if bits < 2048 {
+                return Some(Finding::new(
+                    "weak-rsa-key-size",
+                    Severity::High,
+                    format!("generates a {bits}-bit RSA key, below the 2048-bit minimum"),
+                    file,
+                    line_of(call),
+                ));
+            }
+        }
+    }
+
+    if segments.iter().any(|s| s.to_lowercase().contains("ecb")) {
+        return Some(Finding::new(
+            "weak-crypto-ecb-mode",
+            Severity::High,
+            "uses ECB block cipher mode, which leaks patterns in the plaintext",
+            file,
+            line_of(call),
+        ));
+    }
+
+    if is_cipher_constructor(&segments) {
+        if let Some(arg_line) = call.args.iter().find_map(hardcoded_byte_array_line) {
+            return Some(Finding::new(
+                "weak-crypto-hardcoded-iv",
+                Severity::Medium,
+                "passes a hardcoded byte-array literal as a cipher IV/nonce/salt",
+                file,
+                arg_line,
+            ));
+        }
+    }
+
+    None
+}
+
+fn is_cipher_constructor(segments: &[String]) -> bool {
+    segments.iter().any(|s| {
+        let lower = s.to_lowercase();
+        CIPHER_KEYWORDS.iter().any(|k| lower.contains(k))
+    })
+}
+
+fn literal_int(expr: &Expr) -> Option<u64> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Int(i) => i.base10_parse().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn hardcoded_byte_array_line(expr: &Expr) -> Option<usize> {
+    match expr {
+        Expr::Array(array) if !array.elems.is_empty() => {
+            array.elems.iter().all(literal_int_or_byte).then(|| line_of(array))
+        }
+        // `[0u8; 16]`-style repeat expressions are the idiomatic way to write
+        // a fixed-size hardcoded IV/nonce/salt, same as a comma-separated
+        // literal list.
+        Expr::Repeat(repeat) if literal_int_or_byte(&repeat.expr) => Some(line_of(repeat)),
+        Expr::Reference(r) => hardcoded_byte_array_line(&r.expr),
+        _ => None,
+    }
+}
+
+fn literal_int_or_byte(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Lit(lit) if matches!(lit.lit, Lit::Int(_) | Lit::Byte(_))
+    )
+}
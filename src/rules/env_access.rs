@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use syn::ExprCall;
+
+use super::{call_path_segments, line_of, path_ends_with};
+use crate::finding::Finding;
+use crate::severity::Severity;
+
+/// Flags reads of environment variables, which often carry API keys or
+/// other secrets. On its own this is a LOW-severity observation; it only
+/// becomes dangerous once the value flows somewhere it shouldn't, which the
+/// taint tracker in [`crate::taint`] is responsible for escalating.
+pub fn check(call: &ExprCall, file: &Path) -> Option<Finding> {
+    let segments = call_path_segments(&call.func)?;
+    if path_ends_with(&segments, &["env", "var"]) || path_ends_with(&segments, &["env", "vars"]) {
+        return Some(Finding::new(
+            "env-access",
+            Severity::Low,
+            "reads an environment variable, which may expose a secret if logged or transmitted",
+            file,
+            line_of(call),
+        ));
+    }
+    None
+}
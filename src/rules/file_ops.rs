@@ -0,0 +1,49 @@
+use std::path::Path;
+
+use syn::ExprCall;
+
+use super::{call_path_segments, line_of, path_ends_with};
+use crate::finding::Finding;
+use crate::severity::Severity;
+
+/// Flags filesystem reads (potential credential/source taints) and writes or
+/// deletions (potential data loss or tampering).
+pub fn check(call: &ExprCall, file: &Path) -> Option<Finding> {
+    let segments = call_path_segments(&call.func)?;
+
+    if path_ends_with(&segments, &["fs", "read"])
+        || path_ends_with(&segments, &["fs", "read_to_string"])
+    {
+        return Some(Finding::new(
+            "file-read",
+            Severity::Low,
+            "reads file contents, which may pull in sensitive data",
+            file,
+            line_of(call),
+        ));
+    }
+
+    if path_ends_with(&segments, &["fs", "write"]) {
+        return Some(Finding::new(
+            "file-write",
+            Severity::High,
+            "writes to the filesystem",
+            file,
+            line_of(call),
+        ));
+    }
+
+    if path_ends_with(&segments, &["fs", "remove_file"])
+        || path_ends_with(&segments, &["fs", "remove_dir_all"])
+    {
+        return Some(Finding::new(
+            "file-delete",
+            Severity::High,
+            "deletes files or directories, which is irreversible",
+            file,
+            line_of(call),
+        ));
+    }
+
+    None
+}
@@ -0,0 +1,56 @@
+pub mod crypto;
+pub mod env_access;
+pub mod file_ops;
+pub mod network;
+pub mod regex_safety;
+pub mod shell;
+pub mod unsafe_code;
+pub mod windows;
+
+use syn::spanned::Spanned;
+
+/// Returns the dotted path segments of a call target, e.g. `std::env::var(..)`
+/// yields `["std", "env", "var"]`. Non-path callees (closures, etc.) yield `None`.
+pub(crate) fn call_path_segments(expr: &syn::Expr) -> Option<Vec<String>> {
+    match expr {
+        syn::Expr::Path(p) => Some(
+            p.path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
+/// True if `segments` ends with exactly `suffix`, e.g. `["std", "env", "var"]`
+/// matches suffix `["env", "var"]`. Lets rules match both `env::var(..)` and
+/// fully-qualified `std::env::var(..)` without enumerating every prefix.
+pub(crate) fn path_ends_with(segments: &[String], suffix: &[&str]) -> bool {
+    if segments.len() < suffix.len() {
+        return false;
+    }
+    segments[segments.len() - suffix.len()..]
+        .iter()
+        .zip(suffix)
+        .all(|(a, b)| a == b)
+}
+
+/// 1-based source line of a span, for reporting findings at the call site.
+pub(crate) fn line_of(spanned: &impl Spanned) -> usize {
+    spanned.span().start().line
+}
+
+/// True if an expression is the root or part of a `Command::new(..).arg(..)`
+/// builder chain, so callers can tell a shell-exec `.arg()` call apart from
+/// an unrelated method of the same name.
+pub(crate) fn is_command_chain(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::MethodCall(mc) => is_command_chain(&mc.receiver),
+        syn::Expr::Call(call) => call_path_segments(&call.func)
+            .map(|segments| path_ends_with(&segments, &["Command", "new"]))
+            .unwrap_or(false),
+        _ => false,
+    }
+}
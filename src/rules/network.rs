@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use syn::ExprCall;
+
+use super::{call_path_segments, line_of, path_ends_with};
+use crate::finding::Finding;
+use crate::severity::Severity;
+
+/// Flags outbound network connections: raw TCP sockets and `reqwest` HTTP
+/// calls. These are MEDIUM on their own; [`crate::taint`] escalates to
+/// CRITICAL when a tainted value reaches one of them.
+pub fn check(call: &ExprCall, file: &Path) -> Option<Finding> {
+    let segments = call_path_segments(&call.func)?;
+
+    if path_ends_with(&segments, &["TcpStream", "connect"]) {
+        return Some(Finding::new(
+            "network-connect",
+            Severity::Medium,
+            "opens a raw TCP connection",
+            file,
+            line_of(call),
+        ));
+    }
+
+    if segments.first().map(String::as_str) == Some("reqwest") {
+        return Some(Finding::new(
+            "network-http",
+            Severity::Medium,
+            "makes an outbound HTTP request via reqwest",
+            file,
+            line_of(call),
+        ));
+    }
+
+    None
+}
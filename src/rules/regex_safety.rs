@@ -0,0 +1,138 @@
+use std::path::Path;
+
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprCall, Lit, Macro, Token};
+
+use super::{call_path_segments, line_of, path_ends_with};
+use crate::finding::Finding;
+use crate::severity::Severity;
+
+/// Flags two independent regex footguns on `Regex::new`/`RegexBuilder::new`
+/// calls: a pattern whose source isn't a literal (so its anchoring can't be
+/// verified statically) and, for literal patterns, nested unbounded
+/// quantifiers that risk catastrophic backtracking. Reported separately
+/// since they're unrelated issues a user would act on differently.
+pub fn check(call: &ExprCall, file: &Path) -> Vec<Finding> {
+    let Some(segments) = call_path_segments(&call.func) else {
+        return Vec::new();
+    };
+    if !path_ends_with(&segments, &["Regex", "new"]) && !path_ends_with(&segments, &["RegexBuilder", "new"]) {
+        return Vec::new();
+    }
+    let Some(pattern_arg) = call.args.first() else {
+        return Vec::new();
+    };
+
+    let mut findings = Vec::new();
+    match literal_pattern_text(pattern_arg) {
+        Some(text) => {
+            if has_catastrophic_backtracking_risk(&text) {
+                findings.push(Finding::new(
+                    "regex-catastrophic-backtracking",
+                    Severity::High,
+                    format!("pattern `{text}` nests an unbounded quantifier inside another, risking catastrophic backtracking (ReDoS)"),
+                    file,
+                    line_of(call),
+                ));
+            }
+        }
+        None => {
+            if let Some(message) = dynamic_pattern_message(pattern_arg) {
+                findings.push(Finding::new(
+                    "regex-unanchored-dynamic-pattern",
+                    Severity::Medium,
+                    message,
+                    file,
+                    line_of(call),
+                ));
+            }
+        }
+    }
+    findings
+}
+
+/// Returns the pattern's literal text if it's a plain string literal; `None`
+/// for any other non-literal source (a `format!(..)` call, a variable, a
+/// function call, etc), since the substituted value isn't known statically.
+fn literal_pattern_text(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => Some(s.value()),
+            _ => None,
+        },
+        Expr::Reference(r) => literal_pattern_text(&r.expr),
+        _ => None,
+    }
+}
+
+/// For a pattern built from `format!(..)`, inspects the format string's
+/// literal leading/trailing characters: if they demonstrably anchor the
+/// pattern (`^` at the start, `$` at the end, outside any `{}` placeholder),
+/// the substituted value can't un-anchor it, so there's nothing to flag.
+/// Otherwise — including for any non-`format!` dynamic source, where nothing
+/// about the pattern's shape is known — returns the message to report.
+fn dynamic_pattern_message(expr: &Expr) -> Option<&'static str> {
+    if let Expr::Reference(r) = expr {
+        return dynamic_pattern_message(&r.expr);
+    }
+    if let Expr::Macro(m) = expr {
+        if let Some(template) = format_macro_template(&m.mac) {
+            if template.starts_with('^') && template.ends_with('$') {
+                return None;
+            }
+            return Some(
+                "regex pattern is built with `format!`, and its literal template doesn't start with `^` and end with `$`, so a missing anchor lets crafted input slip past validation if it's matched against untrusted input",
+            );
+        }
+    }
+    Some("regex pattern is built dynamically rather than from a literal, so its anchoring can't be verified; if it's matched against untrusted input, a missing leading `^` lets crafted input slip past validation")
+}
+
+/// The literal format-string template of a `format!(..)` macro call, if its
+/// first argument is a plain string literal.
+fn format_macro_template(mac: &Macro) -> Option<String> {
+    if !mac.path.segments.last()?.ident.to_string().eq("format") {
+        return None;
+    }
+    let args = mac
+        .parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated)
+        .ok()?;
+    match args.first()? {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => Some(s.value()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Heuristically detects nested unbounded quantifiers (`(a+)+`, `(a*)*`) and
+/// alternations repeated under an outer quantifier (`(a|ab)+`), both classic
+/// shapes for catastrophic backtracking.
+fn has_catastrophic_backtracking_risk(pattern: &str) -> bool {
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut group_starts = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 1, // skip escaped char
+            '(' => group_starts.push(i),
+            ')' => {
+                if let Some(start) = group_starts.pop() {
+                    let inner: String = chars[start + 1..i].iter().collect();
+                    let followed_by_quantifier = matches!(chars.get(i + 1), Some('+') | Some('*'));
+                    if followed_by_quantifier {
+                        let has_inner_quantifier = inner.contains('+') || inner.contains('*');
+                        let has_overlapping_alternation = inner.contains('|');
+                        if has_inner_quantifier || has_overlapping_alternation {
+                            return true;
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    false
+}
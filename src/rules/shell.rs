@@ -0,0 +1,26 @@
+use std::path::Path;
+
+use syn::ExprMethodCall;
+
+use super::line_of;
+use crate::finding::Finding;
+use crate::severity::Severity;
+
+/// Flags `Command::new(..).spawn()/.output()/.status()`, i.e. actual shell
+/// execution rather than just building the `Command` value.
+pub fn check(call: &ExprMethodCall, file: &Path) -> Option<Finding> {
+    let method = call.method.to_string();
+    if !matches!(method.as_str(), "spawn" | "output" | "status") {
+        return None;
+    }
+    if !super::is_command_chain(&call.receiver) {
+        return None;
+    }
+    Some(Finding::new(
+        "shell-exec",
+        Severity::Critical,
+        "executes an external process",
+        file,
+        line_of(call),
+    ))
+}
@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use syn::{
+    Block, Expr, ExprCall, ExprCast, FnArg, GenericArgument, Pat, PathArguments, ReturnType,
+    Signature, Stmt, Type,
+};
+
+use super::{call_path_segments, line_of, path_ends_with};
+use crate::finding::Finding;
+use crate::severity::Severity;
+
+/// Primitive element sizes in bytes, used to tell a same-size `transmute`
+/// apart from the genuinely unsound case where a slice's element size
+/// changes and its length isn't rescaled to match.
+fn primitive_size(ty: &Type) -> Option<usize> {
+    let Type::Path(p) = ty else { return None };
+    match p.path.segments.last()?.ident.to_string().as_str() {
+        "u8" | "i8" | "bool" => Some(1),
+        "u16" | "i16" => Some(2),
+        "u32" | "i32" | "f32" | "char" => Some(4),
+        "u64" | "i64" | "f64" | "usize" | "isize" => Some(8),
+        "u128" | "i128" => Some(16),
+        _ => None,
+    }
+}
+
+/// If `ty` is `&[T]`, `&mut [T]`, `*const [T]`, or `*mut [T]`, returns `T`'s
+/// byte size.
+fn slice_element_size(ty: &Type) -> Option<usize> {
+    match ty {
+        Type::Reference(r) => slice_element_size(&r.elem),
+        Type::Ptr(p) => slice_element_size(&p.elem),
+        Type::Slice(s) => primitive_size(&s.elem),
+        _ => None,
+    }
+}
+
+/// The pointer/reference element type itself (not restricted to slices), for
+/// matching chained raw-pointer casts like `as *const u8 as *const u32`.
+fn pointee_element_size(ty: &Type) -> Option<usize> {
+    match ty {
+        Type::Ptr(p) => primitive_size(&p.elem),
+        Type::Reference(r) => primitive_size(&r.elem),
+        _ => None,
+    }
+}
+
+/// Flags UB-prone raw conversions between slices/pointers of differently
+/// sized element types, plus a lower-severity catch-all for any other
+/// `mem::transmute`.
+///
+/// The specific case this targets: `mem::transmute`, `slice::from_raw_parts`
+/// (`_mut`), and chained raw-pointer casts all let you reinterpret a block
+/// of memory as a different element type without rescaling the slice's
+/// length — `&[u8]` of length 4 transmuted to `&[u32]` still claims length
+/// 4, so it's read as 16 bytes, 12 of which are out of bounds.
+///
+/// `param_types` and `dest_type` let the caller supply the enclosing
+/// function's parameter types and an inferred destination type (its return
+/// type, or a `let`-binding's type annotation) so the common
+/// turbofish-free idiom — `fn f(bytes: &[u8]) -> &[u32] { transmute(bytes) }`
+/// — is still caught, not just an explicit `transmute::<Src, Dst>(..)`.
+pub fn check(
+    call: &ExprCall,
+    file: &Path,
+    param_types: &HashMap<String, Type>,
+    dest_type: Option<&Type>,
+) -> Option<Finding> {
+    let segments = call_path_segments(&call.func)?;
+
+    if path_ends_with(&segments, &["mem", "transmute"]) {
+        return Some(check_transmute(call, file, param_types, dest_type));
+    }
+
+    if path_ends_with(&segments, &["slice", "from_raw_parts"])
+        || path_ends_with(&segments, &["slice", "from_raw_parts_mut"])
+    {
+        if let Some(finding) = check_from_raw_parts(call, file) {
+            return Some(finding);
+        }
+    }
+
+    None
+}
+
+fn check_transmute(
+    call: &ExprCall,
+    file: &Path,
+    param_types: &HashMap<String, Type>,
+    dest_type: Option<&Type>,
+) -> Finding {
+    if let Some((src_size, dst_size)) = resolve_transmute_sizes(call, param_types, dest_type) {
+        if src_size != dst_size {
+            return Finding::new(
+                "ub-slice-size-mismatch",
+                Severity::Critical,
+                format!(
+                    "transmutes a slice with {src_size}-byte elements to one with {dst_size}-byte elements; the length isn't rescaled, so the result reads out of bounds"
+                ),
+                file,
+                line_of(call),
+            );
+        }
+    }
+    Finding::new(
+        "unsafe-transmute",
+        Severity::Medium,
+        "transmutes between types, bypassing the type system",
+        file,
+        line_of(call),
+    )
+}
+
+/// Resolves the source and destination slice element sizes for a
+/// `mem::transmute(..)` call, preferring explicit turbofish type arguments
+/// and falling back to the enclosing function's parameter types (for the
+/// argument, if it's a plain identifier) and the caller-supplied
+/// destination type (for the result).
+fn resolve_transmute_sizes(
+    call: &ExprCall,
+    param_types: &HashMap<String, Type>,
+    dest_type: Option<&Type>,
+) -> Option<(usize, usize)> {
+    if let Some((src, dst)) = transmute_turbofish_types(call) {
+        if let (Some(src_size), Some(dst_size)) = (slice_element_size(src), slice_element_size(dst))
+        {
+            return Some((src_size, dst_size));
+        }
+    }
+
+    let arg = call.args.first()?;
+    let Expr::Path(p) = arg else { return None };
+    let src_ty = param_types.get(&p.path.segments.last()?.ident.to_string())?;
+    let dst_ty = dest_type?;
+    Some((slice_element_size(src_ty)?, slice_element_size(dst_ty)?))
+}
+
+/// Extracts the `<Src, Dst>` turbofish type arguments of a
+/// `mem::transmute::<Src, Dst>(..)` call, if present.
+fn transmute_turbofish_types(call: &ExprCall) -> Option<(&Type, &Type)> {
+    let Expr::Path(p) = call.func.as_ref() else {
+        return None;
+    };
+    let last = p.path.segments.last()?;
+    let PathArguments::AngleBracketed(args) = &last.arguments else {
+        return None;
+    };
+    let mut types = args.args.iter().filter_map(|a| match a {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    Some((types.next()?, types.next()?))
+}
+
+/// `slice::from_raw_parts(ptr, len)` is UB-prone in the same way as
+/// `transmute` when `ptr` is a chained cast like `x as *const T as *const U`
+/// with `T` and `U` differently sized — the element type changed but `len`
+/// still counts `T`-sized elements.
+fn check_from_raw_parts(call: &ExprCall, file: &Path) -> Option<Finding> {
+    let ptr_arg = call.args.first()?;
+    let Expr::Cast(outer) = ptr_arg else {
+        return None;
+    };
+    let dst_size = pointee_element_size(&outer.ty)?;
+
+    let Expr::Cast(inner) = outer.expr.as_ref() else {
+        return None;
+    };
+    let src_size = pointee_element_size(&inner.ty)?;
+
+    if src_size == dst_size {
+        return None;
+    }
+    Some(Finding::new(
+        "ub-slice-size-mismatch",
+        Severity::Critical,
+        format!(
+            "builds a slice from a pointer cast from {src_size}-byte elements to {dst_size}-byte elements without rescaling the element count, which reads out of bounds"
+        ),
+        file,
+        line_of(call),
+    ))
+}
+
+/// A standalone `bytes as *const [T] as *const [U]`-style double cast is the
+/// same raw UB as `slice::from_raw_parts` with a mismatched length, just
+/// spelled with `as` directly rather than passed into a call — checked here
+/// independent of [`check_from_raw_parts`] so it's flagged even when the
+/// cast chain isn't immediately a call argument.
+pub fn check_cast(cast: &ExprCast, file: &Path) -> Option<Finding> {
+    let dst_size = slice_element_size(&cast.ty)?;
+
+    let Expr::Cast(inner) = cast.expr.as_ref() else {
+        return None;
+    };
+    let src_size = slice_element_size(&inner.ty)?;
+
+    if src_size == dst_size {
+        return None;
+    }
+    Some(Finding::new(
+        "ub-slice-size-mismatch",
+        Severity::Critical,
+        format!(
+            "casts a pointer from a slice of {src_size}-byte elements to one of {dst_size}-byte elements without rescaling the length, which reads out of bounds"
+        ),
+        file,
+        line_of(cast),
+    ))
+}
+
+/// Maps each simply-named, explicitly-typed parameter of a function to its
+/// type, for resolving a turbofish-free `transmute(arg)`'s source type.
+pub(crate) fn extract_param_types(sig: &Signature) -> HashMap<String, Type> {
+    sig.inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(pat_type) => match pat_type.pat.as_ref() {
+                Pat::Ident(i) => Some((i.ident.to_string(), (*pat_type.ty).clone())),
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect()
+}
+
+/// Maps each `mem::transmute(..)` call whose destination type is knowable
+/// from its syntactic position — the function's tail expression (destination
+/// = the function's return type) or a `let`-binding with an explicit type
+/// annotation (destination = that annotation) — to that destination type.
+/// Keyed by the call's address, since it's resolved while visiting the same
+/// parsed tree the caller will later visit again.
+pub(crate) fn compute_dest_types(sig: &Signature, block: &Block) -> HashMap<usize, Type> {
+    let mut map = HashMap::new();
+
+    if let ReturnType::Type(_, ret_ty) = &sig.output {
+        if let Some(call) = tail_transmute_call(block) {
+            map.insert(call as *const ExprCall as usize, (**ret_ty).clone());
+        }
+    }
+
+    for stmt in &block.stmts {
+        let Stmt::Local(local) = stmt else { continue };
+        let Pat::Type(pat_type) = &local.pat else {
+            continue;
+        };
+        let Some(init) = &local.init else { continue };
+        if let Some(call) = unwrap_to_transmute_call(&init.expr) {
+            map.insert(call as *const ExprCall as usize, (*pat_type.ty).clone());
+        }
+    }
+
+    map
+}
+
+/// The function body's tail expression, if it's (possibly wrapped in an
+/// `unsafe { }`/`{ }` block or `(..)`/`?`/`&`) a direct `mem::transmute` call.
+fn tail_transmute_call(block: &Block) -> Option<&ExprCall> {
+    match block.stmts.last()? {
+        Stmt::Expr(expr, None) => unwrap_to_transmute_call(expr),
+        _ => None,
+    }
+}
+
+fn unwrap_to_transmute_call(expr: &Expr) -> Option<&ExprCall> {
+    match expr {
+        Expr::Call(call) => is_transmute_call(call).then_some(call),
+        Expr::Unsafe(u) => tail_transmute_call(&u.block),
+        Expr::Block(b) => tail_transmute_call(&b.block),
+        Expr::Paren(p) => unwrap_to_transmute_call(&p.expr),
+        Expr::Try(t) => unwrap_to_transmute_call(&t.expr),
+        Expr::Reference(r) => unwrap_to_transmute_call(&r.expr),
+        _ => None,
+    }
+}
+
+fn is_transmute_call(call: &ExprCall) -> bool {
+    call_path_segments(&call.func)
+        .map(|s| path_ends_with(&s, &["mem", "transmute"]))
+        .unwrap_or(false)
+}
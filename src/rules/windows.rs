@@ -0,0 +1,141 @@
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprCall, File as SynFile, Lit, Macro};
+
+use super::line_of;
+use crate::finding::Finding;
+use crate::severity::Severity;
+
+/// Windows process-injection primitives. Any one of these is suspicious on
+/// its own; several appearing in the same file is the signature of a
+/// deliberate injection chain rather than a single benign API use.
+const INJECTION_APIS: &[&str] = &[
+    "VirtualAllocEx",
+    "WriteProcessMemory",
+    "CreateRemoteThread",
+    "QueueUserAPC",
+];
+
+/// Addresses patched in-memory by AV/EDR-evasion shellcode.
+const PATCH_TARGETS: &[&str] = &["AmsiScanBuffer", "EtwEventWrite"];
+
+/// Walks a whole parsed file (rather than one call at a time, like the other
+/// rule modules) because the chain-detection logic needs file-wide context:
+/// it only escalates once several injection primitives co-occur.
+pub struct WindowsAnalyzer<'a> {
+    file: &'a Path,
+    findings: Vec<Finding>,
+    injection_apis_seen: BTreeSet<&'static str>,
+}
+
+impl<'a> WindowsAnalyzer<'a> {
+    pub fn new(file: &'a Path) -> Self {
+        WindowsAnalyzer {
+            file,
+            findings: Vec::new(),
+            injection_apis_seen: BTreeSet::new(),
+        }
+    }
+
+    pub fn into_findings(mut self) -> Vec<Finding> {
+        if self.injection_apis_seen.len() >= 2 {
+            let apis: Vec<_> = self.injection_apis_seen.iter().copied().collect();
+            self.findings.push(Finding::new(
+                "windows-injection-chain",
+                Severity::Critical,
+                format!(
+                    "uses {} together, the signature of a deliberate process injection flow rather than a single benign API call",
+                    apis.join(", ")
+                ),
+                self.file,
+                0,
+            ));
+        }
+        self.findings
+    }
+
+    pub fn run(file: &'a Path, parsed: &SynFile) -> Vec<Finding> {
+        let mut analyzer = WindowsAnalyzer::new(file);
+        analyzer.visit_file(parsed);
+        analyzer.into_findings()
+    }
+}
+
+impl<'a, 'ast> Visit<'ast> for WindowsAnalyzer<'a> {
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        if let Some(name) = callee_name(&call.func) {
+            if let Some(api) = INJECTION_APIS.iter().find(|a| **a == name) {
+                self.injection_apis_seen.insert(api);
+                self.findings.push(Finding::new(
+                    "windows-injection-api",
+                    Severity::High,
+                    format!("calls {api}, a building block of process injection"),
+                    self.file,
+                    line_of(call),
+                ));
+            }
+
+            if name == "GetProcAddress" {
+                if let Some(target) = call
+                    .args
+                    .iter()
+                    .find_map(string_literal)
+                    .filter(|s| PATCH_TARGETS.contains(&s.as_str()))
+                {
+                    self.findings.push(Finding::new(
+                        "windows-amsi-etw-patch",
+                        Severity::Critical,
+                        format!("resolves {target}, commonly patched to disable AMSI/ETW instrumentation"),
+                        self.file,
+                        line_of(call),
+                    ));
+                }
+            }
+
+            if name.to_lowercase().contains("unhook") {
+                self.findings.push(Finding::new(
+                    "windows-ntdll-unhook",
+                    Severity::High,
+                    "calls an unhooking routine, consistent with NTDLL unhooking to evade userland EDR hooks",
+                    self.file,
+                    line_of(call),
+                ));
+            }
+        }
+
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast Macro) {
+        if mac.path.is_ident("asm") || mac.path.is_ident("global_asm") {
+            let tokens = mac.tokens.to_string();
+            if tokens.contains("syscall") {
+                self.findings.push(Finding::new(
+                    "windows-direct-syscall",
+                    Severity::High,
+                    "issues a raw syscall instruction, bypassing the winapi/windows crate wrappers",
+                    self.file,
+                    line_of(mac),
+                ));
+            }
+        }
+        visit::visit_macro(self, mac);
+    }
+}
+
+fn callee_name(expr: &Expr) -> Option<String> {
+    super::call_path_segments(expr)?.last().cloned()
+}
+
+fn string_literal(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Lit(lit) => match &lit.lit {
+            Lit::Str(s) => Some(s.value()),
+            _ => None,
+        },
+        Expr::Reference(r) => string_literal(&r.expr),
+        _ => None,
+    }
+}
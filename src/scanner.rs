@@ -0,0 +1,113 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use syn::visit::{self, Visit};
+use syn::{ExprCall, ExprCast, ExprMethodCall, File as SynFile, ItemFn, Type};
+use walkdir::WalkDir;
+
+use crate::finding::Finding;
+use crate::rules;
+use crate::rules::windows::WindowsAnalyzer;
+use crate::taint::TaintAnalyzer;
+
+/// Scans every `.rs` file under `root` and returns all findings, sorted by
+/// severity (most severe first).
+pub fn scan_path(root: &Path) -> Result<Vec<Finding>> {
+    let mut findings = Vec::new();
+    for entry in WalkDir::new(root)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.path().extension().and_then(|ext| ext.to_str()) == Some("rs"))
+    {
+        findings.extend(scan_file(entry.path())?);
+    }
+    findings.sort_by_key(|f| std::cmp::Reverse(f.severity));
+    Ok(findings)
+}
+
+/// Parses a single source file and runs every rule and the taint analyzer
+/// over it.
+pub fn scan_file(path: &Path) -> Result<Vec<Finding>> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))?;
+    let parsed = syn::parse_file(&source).with_context(|| format!("parsing {}", path.display()))?;
+
+    let mut visitor = CallVisitor {
+        file: path.to_path_buf(),
+        findings: Vec::new(),
+        param_types: HashMap::new(),
+        dest_types: HashMap::new(),
+    };
+    visitor.visit_file(&parsed);
+    let mut findings = visitor.findings;
+
+    findings.extend(run_taint_analysis(&parsed, path));
+    findings.extend(WindowsAnalyzer::run(path, &parsed));
+    Ok(findings)
+}
+
+fn run_taint_analysis(parsed: &SynFile, path: &Path) -> Vec<Finding> {
+    let mut analyzer = TaintAnalyzer::new(path);
+    analyzer.visit_file(parsed);
+    analyzer.into_findings()
+}
+
+/// Visits every call expression in a parsed file, running the per-call
+/// rule checks that don't require cross-statement context.
+///
+/// `param_types` and `dest_types` hold the enclosing function's parameter
+/// types and its transmute calls' inferred destination types (keyed by call
+/// address), recomputed on entry to each `ItemFn` so [`rules::unsafe_code`]
+/// can resolve a turbofish-free `mem::transmute(arg)`'s element sizes from
+/// context instead of only from explicit type arguments.
+struct CallVisitor {
+    file: PathBuf,
+    findings: Vec<Finding>,
+    param_types: HashMap<String, Type>,
+    dest_types: HashMap<usize, Type>,
+}
+
+impl<'ast> Visit<'ast> for CallVisitor {
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        let outer_params = std::mem::replace(
+            &mut self.param_types,
+            rules::unsafe_code::extract_param_types(&item.sig),
+        );
+        let outer_dest_types = std::mem::replace(
+            &mut self.dest_types,
+            rules::unsafe_code::compute_dest_types(&item.sig, &item.block),
+        );
+        visit::visit_item_fn(self, item);
+        self.param_types = outer_params;
+        self.dest_types = outer_dest_types;
+    }
+
+    fn visit_expr_call(&mut self, call: &'ast ExprCall) {
+        self.findings.extend(rules::crypto::check(call, &self.file));
+        self.findings.extend(rules::env_access::check(call, &self.file));
+        self.findings.extend(rules::file_ops::check(call, &self.file));
+        self.findings.extend(rules::network::check(call, &self.file));
+        self.findings.extend(rules::regex_safety::check(call, &self.file));
+        let dest_type = self.dest_types.get(&(call as *const ExprCall as usize));
+        self.findings.extend(rules::unsafe_code::check(
+            call,
+            &self.file,
+            &self.param_types,
+            dest_type,
+        ));
+        visit::visit_expr_call(self, call);
+    }
+
+    fn visit_expr_method_call(&mut self, call: &'ast ExprMethodCall) {
+        self.findings.extend(rules::shell::check(call, &self.file));
+        visit::visit_expr_method_call(self, call);
+    }
+
+    fn visit_expr_cast(&mut self, cast: &'ast ExprCast) {
+        self.findings.extend(rules::unsafe_code::check_cast(cast, &self.file));
+        visit::visit_expr_cast(self, cast);
+    }
+}
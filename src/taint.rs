@@ -0,0 +1,344 @@
+//! Intraprocedural taint tracking.
+//!
+//! The per-call rules in [`crate::rules`] flag `env::var`, `fs::read`, and
+//! `TcpStream::connect` independently, but the dangerous case is a secret
+//! flowing from one into the other. This module walks each function body
+//! statement-by-statement — recursing into `if`/`match`/loop bodies, since a
+//! real leak is usually guarded by a conditional rather than sitting at the
+//! top level — tracks which local bindings carry data that originated from a
+//! taint *source* (`env::var`/`env::vars`, `fs::read`/`fs::read_to_string`, or
+//! a clipboard/credential read like `get_text`/`get_contents`/
+//! `get_password`), and reports a CRITICAL finding when a tainted value
+//! reaches a *sink* (`TcpStream` `.write`/`.write_all`, a `reqwest::*` call,
+//! a `Command::arg`, or a DNS lookup via `.to_socket_addrs()`/
+//! `net::lookup_host`), naming both the line that introduced the taint and
+//! the line that leaked it.
+//!
+//! The analysis is deliberately conservative and local to a single function:
+//! destructuring a tainted tuple/struct taints every binding it introduces,
+//! taint picked up inside a branch or loop body is assumed to persist past
+//! it rather than being scoped away, and any method or function call that
+//! receives a tainted argument or receiver produces a tainted result.
+
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+
+use syn::visit::Visit;
+use syn::{Block, Expr, ExprMethodCall, ItemFn, Local, Pat, Stmt};
+
+use crate::finding::Finding;
+use crate::rules::{call_path_segments, is_command_chain, line_of, path_ends_with};
+use crate::severity::Severity;
+
+const SOURCES: &[&[&str]] = &[
+    &["env", "var"],
+    &["env", "vars"],
+    &["fs", "read"],
+    &["fs", "read_to_string"],
+];
+
+/// Clipboard/credential-store read methods, named by the request alongside
+/// `env::var`/`fs::read` as a taint source: `Clipboard::get_text` (arboard),
+/// `ClipboardProvider::get_contents` (clipboard), and `Entry::get_password`
+/// (keyring). Matched by method name alone, since these are always called on
+/// an instance rather than as an associated-function path call.
+const SOURCE_METHODS: &[&str] = &["get_text", "get_contents", "get_password"];
+
+/// DNS-resolution sink method named by the request alongside `TcpStream`
+/// writes, `reqwest::*`, and `Command` args: `ToSocketAddrs::to_socket_addrs`,
+/// implemented on hostname strings/tuples, resolves a (possibly tainted)
+/// hostname over the network.
+const DNS_SINK_METHOD: &str = "to_socket_addrs";
+
+/// Per-function analysis state: which local bindings carry tainted data
+/// (mapped to the source line that tainted them), and which local bindings
+/// are known to be `TcpStream`s, so a `.write()`/`.write_all()` sink can be
+/// told apart from an ordinary file or buffer write.
+#[derive(Default)]
+struct FnTaint {
+    bindings: HashMap<String, usize>,
+    tcp_streams: HashSet<String>,
+}
+
+pub struct TaintAnalyzer<'a> {
+    file: &'a Path,
+    findings: Vec<Finding>,
+}
+
+impl<'a> TaintAnalyzer<'a> {
+    pub fn new(file: &'a Path) -> Self {
+        TaintAnalyzer {
+            file,
+            findings: Vec::new(),
+        }
+    }
+
+    pub fn into_findings(self) -> Vec<Finding> {
+        self.findings
+    }
+}
+
+impl<'a, 'ast> Visit<'ast> for TaintAnalyzer<'a> {
+    fn visit_item_fn(&mut self, item: &'ast ItemFn) {
+        let mut state = FnTaint::default();
+        walk_block(&item.block, &mut state, self.file, &mut self.findings);
+        // Still recurse so nested fns get their own analysis. Closure bodies
+        // are not walked.
+        syn::visit::visit_item_fn(self, item);
+    }
+}
+
+fn walk_block(block: &Block, state: &mut FnTaint, file: &Path, findings: &mut Vec<Finding>) {
+    for stmt in &block.stmts {
+        walk_stmt(stmt, state, file, findings);
+    }
+}
+
+fn walk_stmt(stmt: &Stmt, state: &mut FnTaint, file: &Path, findings: &mut Vec<Finding>) {
+    match stmt {
+        Stmt::Local(local) => walk_local(local, state, file, findings),
+        Stmt::Expr(expr, _) => walk_expr(expr, state, file, findings),
+        Stmt::Item(_) => {}
+        Stmt::Macro(m) => walk_expr_in_tokens(&m.mac, state, file, findings),
+    }
+}
+
+fn walk_local(local: &Local, state: &mut FnTaint, file: &Path, findings: &mut Vec<Finding>) {
+    let Some(init) = &local.init else {
+        return;
+    };
+    walk_expr(&init.expr, state, file, findings);
+    let tainted_line = taint_of(&init.expr, state);
+    let is_tcp_stream = resolves_to_tcp_stream(&init.expr, state);
+    for name in pattern_idents(&local.pat) {
+        match tainted_line {
+            Some(line) => {
+                state.bindings.insert(name.clone(), line);
+            }
+            None => {
+                // Reassignment/shadowing with an untainted value clears prior taint.
+                state.bindings.remove(&name);
+            }
+        }
+        if is_tcp_stream {
+            state.tcp_streams.insert(name);
+        }
+    }
+}
+
+/// All identifiers a pattern binds, including tuple/struct destructuring.
+/// Every binding a destructured pattern introduces is conservatively taken
+/// to carry the same taint as the whole initializer.
+fn pattern_idents(pat: &Pat) -> Vec<String> {
+    let mut names = Vec::new();
+    collect_pattern_idents(pat, &mut names);
+    names
+}
+
+fn collect_pattern_idents(pat: &Pat, out: &mut Vec<String>) {
+    match pat {
+        Pat::Ident(i) => out.push(i.ident.to_string()),
+        Pat::Tuple(t) => t.elems.iter().for_each(|p| collect_pattern_idents(p, out)),
+        Pat::TupleStruct(t) => t.elems.iter().for_each(|p| collect_pattern_idents(p, out)),
+        Pat::Struct(s) => s
+            .fields
+            .iter()
+            .for_each(|f| collect_pattern_idents(&f.pat, out)),
+        Pat::Reference(r) => collect_pattern_idents(&r.pat, out),
+        Pat::Type(t) => collect_pattern_idents(&t.pat, out),
+        _ => {}
+    }
+}
+
+/// Returns the source line a value is tainted by, if any: a direct call to a
+/// taint source, propagation through a tainted identifier, or propagation
+/// through a call/method-call/`format!`/concatenation involving a tainted
+/// operand.
+fn taint_of(expr: &Expr, state: &FnTaint) -> Option<usize> {
+    match expr {
+        Expr::Path(p) => {
+            let name = p.path.segments.last()?.ident.to_string();
+            state.bindings.get(&name).copied()
+        }
+        Expr::Call(call) => {
+            if let Some(segments) = call_path_segments(&call.func) {
+                if SOURCES.iter().any(|s| path_ends_with(&segments, s)) {
+                    return Some(line_of(call));
+                }
+            }
+            call.args.iter().find_map(|a| taint_of(a, state))
+        }
+        Expr::MethodCall(mc) => {
+            if SOURCE_METHODS.contains(&mc.method.to_string().as_str()) {
+                return Some(line_of(mc));
+            }
+            taint_of(&mc.receiver, state).or_else(|| mc.args.iter().find_map(|a| taint_of(a, state)))
+        }
+        Expr::Binary(b) => taint_of(&b.left, state).or_else(|| taint_of(&b.right, state)),
+        Expr::Reference(r) => taint_of(&r.expr, state),
+        Expr::Try(t) => taint_of(&t.expr, state),
+        Expr::Paren(p) => taint_of(&p.expr, state),
+        Expr::Block(b) => taint_of_block_tail(&b.block, state),
+        Expr::If(i) => taint_of_block_tail(&i.then_branch, state)
+            .or_else(|| i.else_branch.as_ref().and_then(|(_, e)| taint_of(e, state))),
+        Expr::Macro(m) => {
+            // `format!`/`write!`/etc: approximate by checking whether any
+            // tainted binding's name appears among the macro's tokens.
+            let text = m.mac.tokens.to_string();
+            state
+                .bindings
+                .iter()
+                .find(|(name, _)| token_text_mentions(&text, name))
+                .map(|(_, line)| *line)
+        }
+        _ => None,
+    }
+}
+
+/// The taint of a block's final (tail) expression, if it has one and the
+/// block doesn't end with a statement-terminating semicolon.
+fn taint_of_block_tail(block: &Block, state: &FnTaint) -> Option<usize> {
+    match block.stmts.last()? {
+        Stmt::Expr(e, None) => taint_of(e, state),
+        _ => None,
+    }
+}
+
+fn token_text_mentions(tokens: &str, ident: &str) -> bool {
+    tokens
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .any(|tok| tok == ident)
+}
+
+/// True if `expr` resolves to a value that came from `TcpStream::connect`,
+/// looking through `.unwrap()`/`.expect()`/`?` and identifiers already known
+/// to be `TcpStream`s.
+fn resolves_to_tcp_stream(expr: &Expr, state: &FnTaint) -> bool {
+    match expr {
+        Expr::Call(call) => call_path_segments(&call.func)
+            .map(|s| path_ends_with(&s, &["TcpStream", "connect"]))
+            .unwrap_or(false),
+        Expr::MethodCall(mc) => resolves_to_tcp_stream(&mc.receiver, state),
+        Expr::Try(t) => resolves_to_tcp_stream(&t.expr, state),
+        Expr::Reference(r) => resolves_to_tcp_stream(&r.expr, state),
+        Expr::Paren(p) => resolves_to_tcp_stream(&p.expr, state),
+        Expr::Path(p) => p
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| state.tcp_streams.contains(&s.ident.to_string())),
+        _ => false,
+    }
+}
+
+/// Walks an expression looking for sink call shapes (`TcpStream`
+/// `.write`/`.write_all`, any `reqwest::*` call, `Command::arg`, or a DNS
+/// lookup via `.to_socket_addrs()`/`net::lookup_host`), reporting a finding
+/// for each one reached by a tainted operand, and recurses into every
+/// control-flow body (`if`/`match`/loops/nested blocks) so a source-to-sink
+/// flow guarded by a conditional is still caught.
+fn walk_expr(expr: &Expr, state: &mut FnTaint, file: &Path, findings: &mut Vec<Finding>) {
+    match expr {
+        Expr::Call(call) => {
+            if let Some(segments) = call_path_segments(&call.func) {
+                let is_sink = segments.first().map(String::as_str) == Some("reqwest")
+                    || path_ends_with(&segments, &["net", "lookup_host"]);
+                if is_sink {
+                    if let Some(source_line) = call.args.iter().find_map(|a| taint_of(a, state)) {
+                        report(findings, file, source_line, line_of(call));
+                    }
+                }
+            }
+            walk_expr(&call.func, state, file, findings);
+            for arg in &call.args {
+                walk_expr(arg, state, file, findings);
+            }
+        }
+        Expr::MethodCall(mc) => {
+            check_sink_method(mc, state, file, findings);
+            walk_expr(&mc.receiver, state, file, findings);
+            for arg in &mc.args {
+                walk_expr(arg, state, file, findings);
+            }
+        }
+        Expr::Binary(b) => {
+            walk_expr(&b.left, state, file, findings);
+            walk_expr(&b.right, state, file, findings);
+        }
+        Expr::Assign(a) => {
+            walk_expr(&a.right, state, file, findings);
+            // Reassignment re-taints the binding or clears its prior taint.
+            if let Expr::Path(p) = a.left.as_ref() {
+                let name = p.path.segments.last().map(|s| s.ident.to_string()).unwrap_or_default();
+                match taint_of(&a.right, state) {
+                    Some(line) => state.bindings.insert(name, line),
+                    None => state.bindings.remove(&name),
+                };
+            }
+        }
+        Expr::Reference(r) => walk_expr(&r.expr, state, file, findings),
+        Expr::Try(t) => walk_expr(&t.expr, state, file, findings),
+        Expr::Paren(p) => walk_expr(&p.expr, state, file, findings),
+        Expr::Block(b) => walk_block(&b.block, state, file, findings),
+        Expr::If(i) => {
+            walk_expr(&i.cond, state, file, findings);
+            walk_block(&i.then_branch, state, file, findings);
+            if let Some((_, else_expr)) = &i.else_branch {
+                walk_expr(else_expr, state, file, findings);
+            }
+        }
+        Expr::Match(m) => {
+            walk_expr(&m.expr, state, file, findings);
+            for arm in &m.arms {
+                walk_expr(&arm.body, state, file, findings);
+            }
+        }
+        Expr::While(w) => {
+            walk_expr(&w.cond, state, file, findings);
+            walk_block(&w.body, state, file, findings);
+        }
+        Expr::Loop(l) => walk_block(&l.body, state, file, findings),
+        Expr::ForLoop(f) => {
+            walk_expr(&f.expr, state, file, findings);
+            walk_block(&f.body, state, file, findings);
+        }
+        Expr::Macro(m) => walk_expr_in_tokens(&m.mac, state, file, findings),
+        _ => {}
+    }
+}
+
+/// `format!`/`write!`/etc invoked as a bare statement: nothing in the repo's
+/// supported source/sink set is itself a macro, so there's nothing further
+/// to walk into — the macro's tokens are opaque to `syn` once parsed this
+/// way.
+fn walk_expr_in_tokens(_mac: &syn::Macro, _state: &mut FnTaint, _file: &Path, _findings: &mut Vec<Finding>) {}
+
+fn check_sink_method(mc: &ExprMethodCall, state: &FnTaint, file: &Path, findings: &mut Vec<Finding>) {
+    let method = mc.method.to_string();
+    let is_sink = match method.as_str() {
+        "write" | "write_all" => resolves_to_tcp_stream(&mc.receiver, state),
+        "arg" => is_command_chain(&mc.receiver),
+        m if m == DNS_SINK_METHOD => true,
+        _ => false,
+    };
+    if !is_sink {
+        return;
+    }
+    let receiver_tainted = taint_of(&mc.receiver, state);
+    let arg_tainted = mc.args.iter().find_map(|a| taint_of(a, state));
+    if let Some(source_line) = arg_tainted.or(receiver_tainted) {
+        report(findings, file, source_line, line_of(mc));
+    }
+}
+
+fn report(findings: &mut Vec<Finding>, file: &Path, source_line: usize, sink_line: usize) {
+    findings.push(Finding::new(
+        "taint-exfiltration",
+        Severity::Critical,
+        format!(
+            "tainted value from line {source_line} reaches a network sink here (possible exfiltration)"
+        ),
+        file,
+        sink_line,
+    ));
+}
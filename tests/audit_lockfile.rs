@@ -0,0 +1,30 @@
+use std::path::Path;
+
+#[test]
+fn flags_known_vulnerable_dependency() {
+    let findings =
+        skillguard::dependency::audit_lockfile(Path::new("tests/fixtures/vulnerable.lock"))
+            .expect("audit should succeed");
+
+    assert!(
+        findings.iter().any(|f| f.message.contains("RUSTSEC-2020-0071")),
+        "expected the vulnerable `time` advisory to fire, got {findings:?}"
+    );
+    assert!(
+        !findings.iter().any(|f| f.message.starts_with("serde@")),
+        "serde is not in the offline snapshot and should not be flagged, got {findings:?}"
+    );
+}
+
+#[test]
+fn provenance_record_round_trips_through_json() {
+    let packages = skillguard::dependency::lockfile::parse(Path::new(
+        "tests/fixtures/vulnerable.lock",
+    ))
+    .unwrap();
+    let record = skillguard::dependency::provenance::ProvenanceRecord::from_packages(&packages);
+    let json = record.to_json().unwrap();
+
+    assert!(json.contains("\"time\""));
+    assert!(json.contains("0.2.22"));
+}
@@ -0,0 +1,19 @@
+fn validate(user_pattern: &str, input: &str) -> bool {
+    let re = Regex::new(user_pattern).unwrap();
+    re.is_match(input)
+}
+
+fn check_email(input: &str) -> bool {
+    let re = Regex::new(r"(a+)+b").unwrap();
+    re.is_match(input)
+}
+
+fn validate_prefix(prefix: &str, input: &str) -> bool {
+    let re = Regex::new(&format!("^{}$", prefix)).unwrap();
+    re.is_match(input)
+}
+
+fn validate_suffix(suffix: &str, input: &str) -> bool {
+    let re = Regex::new(&format!("{}$", suffix)).unwrap();
+    re.is_match(input)
+}
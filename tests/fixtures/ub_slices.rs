@@ -0,0 +1,19 @@
+fn reinterpret(bytes: &[u8]) -> &[u32] {
+    unsafe { std::mem::transmute::<&[u8], &[u32]>(bytes) }
+}
+
+fn same_size(x: u32) -> i32 {
+    unsafe { std::mem::transmute::<u32, i32>(x) }
+}
+
+fn raw_parts(bytes: &[u8]) -> &[u32] {
+    unsafe { std::slice::from_raw_parts(bytes.as_ptr() as *const u8 as *const u32, bytes.len() / 4) }
+}
+
+fn reinterpret_inferred(bytes: &[u8]) -> &[u32] {
+    unsafe { std::mem::transmute(bytes) }
+}
+
+fn standalone_cast(bytes: &[u8]) -> *const [u32] {
+    bytes as *const [u8] as *const [u32]
+}
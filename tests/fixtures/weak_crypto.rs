@@ -0,0 +1,18 @@
+fn hash_password(password: &str) -> Vec<u8> {
+    md5::compute(password.as_bytes()).to_vec()
+}
+
+fn gen_key() -> RsaPrivateKey {
+    let mut rng = rand::thread_rng();
+    RsaPrivateKey::new(&mut rng, 1024).unwrap()
+}
+
+fn encrypt(key: &[u8]) {
+    let cipher = Aes128Ecb::new_from_slices(key, &[0u8; 16]).unwrap();
+    let _ = cipher;
+}
+
+fn encrypt_cbc(key: &[u8]) {
+    let cipher = Aes128Cbc::new_from_slices(key, &[0u8; 16]).unwrap();
+    let _ = cipher;
+}
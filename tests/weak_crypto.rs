@@ -0,0 +1,24 @@
+use std::path::Path;
+
+#[test]
+fn flags_weak_crypto_patterns() {
+    let findings = skillguard::scanner::scan_file(Path::new("tests/fixtures/weak_crypto.rs"))
+        .expect("scan should succeed");
+
+    assert!(
+        findings.iter().any(|f| f.rule_id == "weak-crypto-algorithm"),
+        "expected a weak-crypto-algorithm finding, got {findings:?}"
+    );
+    assert!(
+        findings.iter().any(|f| f.rule_id == "weak-rsa-key-size"),
+        "expected a weak-rsa-key-size finding, got {findings:?}"
+    );
+    assert!(
+        findings.iter().any(|f| f.rule_id == "weak-crypto-ecb-mode"),
+        "expected a weak-crypto-ecb-mode finding, got {findings:?}"
+    );
+    assert!(
+        findings.iter().any(|f| f.rule_id == "weak-crypto-hardcoded-iv"),
+        "expected a weak-crypto-hardcoded-iv finding for the `[0u8; 16]` repeat-expression IV, got {findings:?}"
+    );
+}
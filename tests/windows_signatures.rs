@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use skillguard::severity::Severity;
+
+#[test]
+fn flags_injection_chain_and_amsi_patch() {
+    let findings =
+        skillguard::scanner::scan_file(Path::new("tests/fixtures/windows_injection.rs"))
+            .expect("scan should succeed");
+
+    assert!(
+        findings.iter().any(|f| f.rule_id == "windows-injection-api"),
+        "expected individual windows-injection-api findings, got {findings:?}"
+    );
+    assert!(
+        findings
+            .iter()
+            .any(|f| f.rule_id == "windows-injection-chain" && f.severity == Severity::Critical),
+        "expected a chain escalation once several injection APIs co-occur, got {findings:?}"
+    );
+    assert!(
+        findings.iter().any(|f| f.rule_id == "windows-amsi-etw-patch"),
+        "expected an AMSI patch finding, got {findings:?}"
+    );
+}